@@ -10,19 +10,150 @@
 /// implements a `Visitor` must implement the visit_name
 /// a visitor must be able to visit any kind of node, so any
 /// visitor must implement all these functions
-pub trait Visitor {
-    fn visit_document(&mut self, d: &Document);
-    fn visit_definition(&mut self, d: &Definition);
-    fn visit_name(&mut self, n: &Name);
-    fn visit_scalar_type(&mut self, st: &ScalarType);
-    fn visit_type_definition(&mut self, td: &TypeDefinition);
+///
+/// Every method has a default body that just calls the matching
+/// `walk_*` free function (except `visit_name`, a leaf with no `walk_*`
+/// counterpart, which returns `Output::default()`), so a visitor only
+/// needs to override the
+/// nodes it actually cares about and still gets a full-tree walk for
+/// free. Overriding a method without calling its `walk_*` counterpart
+/// prunes that subtree from the traversal.
+///
+/// `Output` lets a visitor fold a result out of the tree (a printed
+/// `String`, a `Result<(), Vec<Error>>` from a validator, a node count, …)
+/// instead of smuggling it out through a field on the visitor struct. The
+/// default bodies below only thread a single child's output through;
+/// visitors that need to combine more than one child's output (e.g. a
+/// printer concatenating every top-level definition) override the
+/// relevant `visit_*` directly rather than relying on `walk_*`.
+///
+/// `'ast` ties every node reference handed to a `visit_*` method back to
+/// the tree being walked, so an `Output` (like `SymbolIndex`'s) can carry
+/// borrowed node references out of the traversal instead of only owned
+/// values.
+pub trait Visitor<'ast> {
+    type Output: Default;
+
+    fn visit_document(&mut self, d: &'ast Document) -> Self::Output {
+        walk_document(self, d)
+    }
+    fn visit_definition(&mut self, d: &'ast Definition) -> Self::Output {
+        walk_definition(self, d)
+    }
+    fn visit_name(&mut self, _n: &'ast Name) -> Self::Output {
+        Self::Output::default()
+    }
+    fn visit_scalar_type(&mut self, st: &'ast ScalarType) -> Self::Output {
+        walk_scalar_type(self, st)
+    }
+    fn visit_object_type(&mut self, ot: &'ast ObjectType) -> Self::Output {
+        walk_object_type(self, ot)
+    }
+    fn visit_field(&mut self, f: &'ast Field) -> Self::Output {
+        walk_field(self, f)
+    }
+    fn visit_type(&mut self, t: &'ast Type) -> Self::Output {
+        walk_type(self, t)
+    }
+    fn visit_type_definition(&mut self, td: &'ast TypeDefinition) -> Self::Output {
+        walk_type_definition(self, td)
+    }
+}
+
+/// Walks every `Definition` in `d.definitions`, keeping the last one's
+/// output (or the default if there are none).
+pub fn walk_document<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, d: &'ast Document) -> V::Output {
+    let mut output = V::Output::default();
+    for definition in &d.definitions {
+        output = v.visit_definition(definition);
+    }
+    output
+}
+
+/// Descends into whichever kind of definition `d` is.
+pub fn walk_definition<'ast, V: Visitor<'ast> + ?Sized>(
+    v: &mut V,
+    d: &'ast Definition,
+) -> V::Output {
+    match d {
+        Definition::TypeDefinition(td) => v.visit_type_definition(td),
+    }
+}
+
+/// Descends into whichever kind of type definition `td` is.
+pub fn walk_type_definition<'ast, V: Visitor<'ast> + ?Sized>(
+    v: &mut V,
+    td: &'ast TypeDefinition,
+) -> V::Output {
+    match td {
+        TypeDefinition::Scalar(st) => v.visit_scalar_type(st),
+        TypeDefinition::Object(ot) => v.visit_object_type(ot),
+    }
+}
+
+/// Visits an `ObjectType`'s name, then each of its fields in order,
+/// keeping the last field's output (or the default if there are none).
+pub fn walk_object_type<'ast, V: Visitor<'ast> + ?Sized>(
+    v: &mut V,
+    ot: &'ast ObjectType,
+) -> V::Output {
+    v.visit_name(&ot.name);
+    let mut output = V::Output::default();
+    for field in &ot.fields {
+        output = v.visit_field(field);
+    }
+    output
+}
+
+/// Visits a `Field`'s name, then its type, keeping the type's output.
+pub fn walk_field<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, f: &'ast Field) -> V::Output {
+    v.visit_name(&f.name);
+    v.visit_type(&f.field_type)
+}
+
+/// Descends into whichever kind of type modifier `t` is.
+pub fn walk_type<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, t: &'ast Type) -> V::Output {
+    match t {
+        Type::NamedType(n) => v.visit_name(n),
+        Type::ListType(inner) => v.visit_type(inner),
+        Type::NonNullType(inner) => v.visit_type(inner),
+    }
+}
+
+/// Visits a `ScalarType`'s name.
+pub fn walk_scalar_type<'ast, V: Visitor<'ast> + ?Sized>(
+    v: &mut V,
+    st: &'ast ScalarType,
+) -> V::Output {
+    v.visit_name(&st.name)
 }
 
 /// Any struct (ASTNodes) that implement this trait much allow
 /// the user to implement the accept fn.
 pub trait ASTNode {
     // accept can be implemented to take any struct that implements Visitor
-    fn accept<V: Visitor>(&self, v: &mut V);
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output;
+}
+
+/// The mutable counterpart to `Visitor`. Where `Visitor` walks a read-only
+/// tree (handy for printers), `VisitorMut` walks the tree in place, so
+/// passes like renaming scalars or stripping descriptions don't need to
+/// rebuild the AST from scratch.
+pub trait VisitorMut {
+    fn visit_document(&mut self, d: &mut Document);
+    fn visit_definition(&mut self, d: &mut Definition);
+    fn visit_name(&mut self, n: &mut Name);
+    fn visit_scalar_type(&mut self, st: &mut ScalarType);
+    fn visit_object_type(&mut self, ot: &mut ObjectType);
+    fn visit_field(&mut self, f: &mut Field);
+    fn visit_type(&mut self, t: &mut Type);
+    fn visit_type_definition(&mut self, td: &mut TypeDefinition);
+}
+
+/// The mutable counterpart to `ASTNode`. Implementors hand a `&mut self`
+/// to the visitor instead of a `&self`.
+pub trait ASTNodeMut {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V);
 }
 
 // // // // // // // //
@@ -39,7 +170,12 @@ pub struct Document {
     definitions: Vec<Definition>,
 }
 impl ASTNode for Document {
-    fn accept<V: Visitor>(&self, v: &mut V) {
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_document(self)
+    }
+}
+impl ASTNodeMut for Document {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
         v.visit_document(self);
     }
 }
@@ -50,7 +186,12 @@ pub enum Definition {
     TypeDefinition(TypeDefinition),
 }
 impl ASTNode for Definition {
-    fn accept<V: Visitor>(&self, v: &mut V) {
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_definition(self)
+    }
+}
+impl ASTNodeMut for Definition {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
         v.visit_definition(self);
     }
 }
@@ -59,70 +200,154 @@ impl ASTNode for Definition {
 
 pub enum TypeDefinition {
     Scalar(ScalarType),
-    // Object()
+    Object(ObjectType),
 }
 impl ASTNode for TypeDefinition {
-    fn accept<V: Visitor>(&self, v: &mut V) {
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_type_definition(self)
+    }
+}
+impl ASTNodeMut for TypeDefinition {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
         v.visit_type_definition(self);
     }
 }
 
+// ---------- Source Locations ----------
+
+/// A line/column location in the source text a node was parsed from.
+/// `Pos::default()` (0:0) is the sentinel used by hand-built ASTs, like
+/// the one in `main`, that were never parsed from source.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Pos {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Implemented by every AST node that carries a `Pos`, so a visitor (or
+/// a diagnostic) can ask where a node came from without matching on its
+/// concrete type.
+pub trait Located {
+    fn position(&self) -> Pos;
+}
+
 // ---------- Meta Nodes ----------
 
 /// The name is a simple ast node that does nothing but
 /// mention the name of the parent
 #[derive(Debug)]
 pub struct Name {
+    pub position: Pos,
     pub value: String,
 }
 /// `Name` is a node, so I want to add the `accept` fn to that node
 impl ASTNode for Name {
-    fn accept<V: Visitor>(&self, v: &mut V) {
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_name(self)
+    }
+}
+impl ASTNodeMut for Name {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
         v.visit_name(self);
     }
 }
+impl Located for Name {
+    fn position(&self) -> Pos {
+        self.position
+    }
+}
 
 #[derive(Debug)]
 pub struct Field {
-    // pub position: Pos,
+    pub position: Pos,
     pub description: Option<String>,
     pub name: Name,
     // pub arguments: Vec<InputValue<'a, T>>,
     pub field_type: Type,
     // pub directives: Vec<Directive<'a, T>>,
 }
+impl ASTNode for Field {
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_field(self)
+    }
+}
+impl ASTNodeMut for Field {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
+        v.visit_field(self);
+    }
+}
+impl Located for Field {
+    fn position(&self) -> Pos {
+        self.position
+    }
+}
 
 // ---------- Types ----------
 
 #[derive(Debug)]
 pub enum Type {
-    NamedType,
+    NamedType(Name),
     ListType(Box<Type>),
     NonNullType(Box<Type>),
 }
+impl ASTNode for Type {
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_type(self)
+    }
+}
+impl ASTNodeMut for Type {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
+        v.visit_type(self);
+    }
+}
 
 #[derive(Debug)]
 pub struct ScalarType {
+    pub position: Pos,
     pub description: Option<String>,
     pub name: Name,
 }
 impl ASTNode for ScalarType {
-    fn accept<V: Visitor>(&self, v: &mut V) {
-        v.visit_scalar_type(&self);
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_scalar_type(self)
+    }
+}
+impl ASTNodeMut for ScalarType {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
+        v.visit_scalar_type(self);
+    }
+}
+impl Located for ScalarType {
+    fn position(&self) -> Pos {
+        self.position
     }
 }
 
 #[derive(Debug)]
 pub struct ObjectType {
+    pub position: Pos,
     pub description: Option<String>,
     pub name: Name,
     pub fields: Vec<Field>,
-    // pub position: Pos,
-    // pub description: Option<String>,
     // pub name: T::Value,
     // pub implements_interfaces: Vec<T::Value>,
     // pub directives: Vec<Directive<'a, T>>,
 }
+impl ASTNode for ObjectType {
+    fn accept<'ast, V: Visitor<'ast>>(&'ast self, v: &mut V) -> V::Output {
+        v.visit_object_type(self)
+    }
+}
+impl ASTNodeMut for ObjectType {
+    fn accept_mut<V: VisitorMut>(&mut self, v: &mut V) {
+        v.visit_object_type(self);
+    }
+}
+impl Located for ObjectType {
+    fn position(&self) -> Pos {
+        self.position
+    }
+}
 
 // // // // // // // //
 // // // // // // // //
@@ -133,68 +358,434 @@ pub struct ObjectType {
 // // // // // // // //
 
 fn print<N: ASTNode>(n: &N) -> String {
-    struct Printer {
-        output: String,
+    struct Printer;
+
+    impl<'ast> Visitor<'ast> for Printer {
+        type Output = String;
+
+        fn visit_document(&mut self, d: &'ast Document) -> String {
+            let mut output = String::new();
+            for definition in &d.definitions {
+                output.push_str(&self.visit_definition(definition));
+            }
+            output
+        }
+        fn visit_name(&mut self, n: &'ast Name) -> String {
+            n.value.clone()
+        }
+        fn visit_scalar_type(&mut self, st: &'ast ScalarType) -> String {
+            let mut output = String::new();
+            if let Some(description) = &st.description {
+                output.push_str(&format!("\"\"\"\n{}\n\"\"\"\n", description));
+            }
+            output.push_str("scalar ");
+            output.push_str(&self.visit_name(&st.name));
+            output.push('\n');
+            output
+        }
+        fn visit_object_type(&mut self, ot: &'ast ObjectType) -> String {
+            let mut output = String::new();
+            if let Some(description) = &ot.description {
+                output.push_str(&format!("\"\"\"\n{}\n\"\"\"\n", description));
+            }
+            output.push_str("type ");
+            output.push_str(&self.visit_name(&ot.name));
+            output.push_str(" {\n");
+            for field in &ot.fields {
+                output.push_str("  ");
+                output.push_str(&self.visit_field(field));
+                output.push('\n');
+            }
+            output.push_str("}\n");
+            output
+        }
+        fn visit_field(&mut self, f: &'ast Field) -> String {
+            format!(
+                "{}: {}",
+                self.visit_name(&f.name),
+                self.visit_type(&f.field_type)
+            )
+        }
+        fn visit_type(&mut self, t: &'ast Type) -> String {
+            match t {
+                Type::NamedType(n) => self.visit_name(n),
+                Type::ListType(inner) => format!("[{}]", self.visit_type(inner)),
+                Type::NonNullType(inner) => format!("{}!", self.visit_type(inner)),
+            }
+        }
     }
 
-    impl Visitor for Printer {
-        fn visit_name(&mut self, n: &Name) {
-            self.output.push_str(&n.value);
+    n.accept(&mut Printer)
+}
+
+// // // // // // // //
+// // // // // // // //
+// // // // // // // //
+// THE DESCRIPTION CLEARER //
+// // // // // // // //
+// // // // // // // //
+// // // // // // // //
+
+/// Strips every `description` out of `n` in place, demonstrating
+/// `VisitorMut`: unlike `print`, this mutates the tree instead of
+/// folding a value out of it.
+fn clear_descriptions<N: ASTNodeMut>(n: &mut N) {
+    struct DescriptionClearer;
+
+    impl VisitorMut for DescriptionClearer {
+        fn visit_document(&mut self, d: &mut Document) {
+            for definition in &mut d.definitions {
+                self.visit_definition(definition);
+            }
+        }
+        fn visit_definition(&mut self, d: &mut Definition) {
+            match d {
+                Definition::TypeDefinition(td) => self.visit_type_definition(td),
+            }
         }
-        fn visit_scalar_type(&mut self, st: &ScalarType) {
-            match &st.description {
-                Some(description) => self
-                    .output
-                    .push_str(&format!("\"\"\"\n{}\n\"\"\"\n", description)),
-                None => {}
+        fn visit_name(&mut self, _n: &mut Name) {}
+        fn visit_scalar_type(&mut self, st: &mut ScalarType) {
+            st.description = None;
+        }
+        fn visit_object_type(&mut self, ot: &mut ObjectType) {
+            ot.description = None;
+            for field in &mut ot.fields {
+                self.visit_field(field);
             }
-            Printer::visit_name(self, &st.name);
-            self.output.push_str(";\n");
         }
-        fn visit_type_definition(&mut self, td: &TypeDefinition) {
+        fn visit_field(&mut self, f: &mut Field) {
+            f.description = None;
+        }
+        fn visit_type(&mut self, _t: &mut Type) {}
+        fn visit_type_definition(&mut self, td: &mut TypeDefinition) {
             match td {
-                TypeDefinition::Scalar(st) => {
-                    Printer::visit_scalar_type(self, st);
-                }
+                TypeDefinition::Scalar(st) => self.visit_scalar_type(st),
+                TypeDefinition::Object(ot) => self.visit_object_type(ot),
             }
         }
-        fn visit_definition(&mut self, d: &Definition) {
-            match d {
-                Definition::TypeDefinition(td) => {
-                    Printer::visit_type_definition(self, td);
+    }
+
+    n.accept_mut(&mut DescriptionClearer);
+}
+
+// // // // // // // //
+// // // // // // // //
+// // // // // // // //
+//  THE SYMBOL INDEX   //
+// // // // // // // //
+// // // // // // // //
+// // // // // // // //
+
+/// A name-indexed view over a `Document`, built once so a type definition
+/// can be found by name without re-walking the whole tree. Lookups are
+/// case-insensitive, since GraphQL type names are conventionally
+/// PascalCase but authors don't always get the case right when referring
+/// to them from elsewhere.
+///
+/// `'a` ties every indexed reference back to the `Document` it was built
+/// from, so the index can't outlive the tree it describes.
+pub struct SymbolIndex<'a> {
+    entries: Vec<(String, &'a TypeDefinition)>,
+}
+
+impl<'a> SymbolIndex<'a> {
+    /// Indexes every type definition in `document` by its lowercased name.
+    ///
+    /// Built by a `Visitor` rather than a hand-rolled walk, so the index
+    /// stays in sync with the AST shape: `visit_document` concatenates
+    /// each definition's entries (mirroring how the printer concatenates
+    /// its children), while `visit_type_definition` does the actual
+    /// collecting and prunes the walk, since no type definition has
+    /// children worth indexing.
+    pub fn from_document(document: &'a Document) -> Self {
+        struct Builder;
+
+        impl<'ast> Visitor<'ast> for Builder {
+            type Output = Vec<(String, &'ast TypeDefinition)>;
+
+            fn visit_document(&mut self, d: &'ast Document) -> Self::Output {
+                let mut entries = Vec::new();
+                for definition in &d.definitions {
+                    entries.extend(self.visit_definition(definition));
                 }
+                entries
             }
-        }
-        fn visit_document(&mut self, d: &Document) {
-            for definition in &d.definitions {
-                Printer::visit_definition(self, definition);
+            fn visit_type_definition(&mut self, td: &'ast TypeDefinition) -> Self::Output {
+                let name = match td {
+                    TypeDefinition::Scalar(st) => &st.name.value,
+                    TypeDefinition::Object(ot) => &ot.name.value,
+                };
+                vec![(name.to_lowercase(), td)]
             }
         }
+
+        let entries = document.accept(&mut Builder);
+        SymbolIndex { entries }
     }
 
-    let mut print_schema = Printer {
-        output: std::string::String::new(),
-    };
-    n.accept(&mut print_schema);
-    print_schema.output
+    /// Finds the type definition named `name`, ignoring case.
+    pub fn lookup(&self, name: &str) -> Option<&'a TypeDefinition> {
+        let needle = name.to_lowercase();
+        self.entries
+            .iter()
+            .find(|(indexed_name, _)| *indexed_name == needle)
+            .map(|(_, td)| *td)
+    }
+
+    /// Finds every type definition whose name starts with `prefix`,
+    /// ignoring case.
+    pub fn search_prefix(&self, prefix: &str) -> Vec<&'a TypeDefinition> {
+        let needle = prefix.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|(indexed_name, _)| indexed_name.starts_with(&needle))
+            .map(|(_, td)| *td)
+            .collect()
+    }
 }
 
 fn main() {
-    let my_document = Document {
+    let mut my_document = Document {
         definitions: vec![
             Definition::TypeDefinition(TypeDefinition::Scalar(ScalarType {
+                position: Pos::default(),
                 description: Some("a value that we can use :)".to_string()),
                 name: Name {
+                    position: Pos::default(),
                     value: "MyScalar".to_string(),
                 },
             })),
             Definition::TypeDefinition(TypeDefinition::Scalar(ScalarType {
+                position: Pos::default(),
                 description: None,
                 name: Name {
+                    position: Pos::default(),
                     value: "AnotherScalar".to_string(),
                 },
             })),
+            Definition::TypeDefinition(TypeDefinition::Object(ObjectType {
+                position: Pos::default(),
+                description: None,
+                name: Name {
+                    position: Pos::default(),
+                    value: "Foo".to_string(),
+                },
+                fields: vec![Field {
+                    position: Pos::default(),
+                    description: None,
+                    name: Name {
+                        position: Pos::default(),
+                        value: "bar".to_string(),
+                    },
+                    field_type: Type::NonNullType(Box::new(Type::ListType(Box::new(
+                        Type::NonNullType(Box::new(Type::NamedType(Name {
+                            position: Pos::default(),
+                            value: "Baz".to_string(),
+                        }))),
+                    )))),
+                }],
+            })),
         ],
     };
     println!("{}", print(&my_document));
-}
\ No newline at end of file
+
+    let symbols = SymbolIndex::from_document(&my_document);
+    if let Some(found) = symbols.lookup("myscalar") {
+        println!("lookup(\"myscalar\") -> {}", print(found));
+    }
+    for td in symbols.search_prefix("a") {
+        println!("search_prefix(\"a\") -> {}", print(td));
+    }
+
+    clear_descriptions(&mut my_document);
+    println!("after clear_descriptions:\n{}", print(&my_document));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar(name: &str, description: Option<&str>) -> Definition {
+        Definition::TypeDefinition(TypeDefinition::Scalar(ScalarType {
+            position: Pos::default(),
+            description: description.map(|d| d.to_string()),
+            name: Name {
+                position: Pos::default(),
+                value: name.to_string(),
+            },
+        }))
+    }
+
+    #[test]
+    fn print_scalar_with_description() {
+        let scalar_type = ScalarType {
+            position: Pos::default(),
+            description: Some("a value that we can use :)".to_string()),
+            name: Name {
+                position: Pos::default(),
+                value: "MyScalar".to_string(),
+            },
+        };
+
+        assert_eq!(
+            print(&scalar_type),
+            "\"\"\"\na value that we can use :)\n\"\"\"\nscalar MyScalar\n"
+        );
+    }
+
+    #[test]
+    fn print_object_with_fields() {
+        let object_type = ObjectType {
+            position: Pos::default(),
+            description: None,
+            name: Name {
+                position: Pos::default(),
+                value: "Foo".to_string(),
+            },
+            fields: vec![Field {
+                position: Pos::default(),
+                description: None,
+                name: Name {
+                    position: Pos::default(),
+                    value: "bar".to_string(),
+                },
+                field_type: Type::NonNullType(Box::new(Type::ListType(Box::new(
+                    Type::NonNullType(Box::new(Type::NamedType(Name {
+                        position: Pos::default(),
+                        value: "Baz".to_string(),
+                    }))),
+                )))),
+            }],
+        };
+
+        assert_eq!(print(&object_type), "type Foo {\n  bar: [Baz!]!\n}\n");
+    }
+
+    #[test]
+    fn print_wraps_list_and_non_null_type_modifiers() {
+        let field_type = Type::NonNullType(Box::new(Type::ListType(Box::new(Type::NonNullType(
+            Box::new(Type::NamedType(Name {
+                position: Pos::default(),
+                value: "Baz".to_string(),
+            })),
+        )))));
+
+        assert_eq!(print(&field_type), "[Baz!]!");
+    }
+
+    #[test]
+    fn clear_descriptions_strips_scalar_and_object_descriptions() {
+        let mut document = Document {
+            definitions: vec![
+                scalar("MyScalar", Some("a value that we can use :)")),
+                Definition::TypeDefinition(TypeDefinition::Object(ObjectType {
+                    position: Pos::default(),
+                    description: Some("a thing".to_string()),
+                    name: Name {
+                        position: Pos::default(),
+                        value: "Foo".to_string(),
+                    },
+                    fields: vec![Field {
+                        position: Pos::default(),
+                        description: Some("a field".to_string()),
+                        name: Name {
+                            position: Pos::default(),
+                            value: "bar".to_string(),
+                        },
+                        field_type: Type::NamedType(Name {
+                            position: Pos::default(),
+                            value: "Baz".to_string(),
+                        }),
+                    }],
+                })),
+            ],
+        };
+
+        clear_descriptions(&mut document);
+
+        match &document.definitions[0] {
+            Definition::TypeDefinition(TypeDefinition::Scalar(st)) => {
+                assert_eq!(st.description, None);
+            }
+            _ => panic!("expected a scalar definition"),
+        }
+        match &document.definitions[1] {
+            Definition::TypeDefinition(TypeDefinition::Object(ot)) => {
+                assert_eq!(ot.description, None);
+                assert_eq!(ot.fields[0].description, None);
+            }
+            _ => panic!("expected an object definition"),
+        }
+    }
+
+    fn document_with_names(names: &[&str]) -> Document {
+        Document {
+            definitions: names.iter().map(|name| scalar(name, None)).collect(),
+        }
+    }
+
+    #[test]
+    fn lookup_finds_an_exact_match() {
+        let document = document_with_names(&["MyScalar", "AnotherScalar"]);
+        let symbols = SymbolIndex::from_document(&document);
+
+        let found = symbols
+            .lookup("MyScalar")
+            .expect("MyScalar should be indexed");
+        match found {
+            TypeDefinition::Scalar(st) => assert_eq!(st.name.value, "MyScalar"),
+            _ => panic!("expected a scalar definition"),
+        }
+    }
+
+    #[test]
+    fn lookup_is_case_insensitive() {
+        let document = document_with_names(&["MyScalar"]);
+        let symbols = SymbolIndex::from_document(&document);
+
+        assert!(symbols.lookup("myscalar").is_some());
+        assert!(symbols.lookup("MYSCALAR").is_some());
+    }
+
+    #[test]
+    fn lookup_returns_none_for_an_unknown_name() {
+        let document = document_with_names(&["MyScalar"]);
+        let symbols = SymbolIndex::from_document(&document);
+
+        assert!(symbols.lookup("NoSuchType").is_none());
+    }
+
+    #[test]
+    fn search_prefix_with_empty_prefix_returns_everything() {
+        let document = document_with_names(&["MyScalar", "AnotherScalar"]);
+        let symbols = SymbolIndex::from_document(&document);
+
+        assert_eq!(symbols.search_prefix("").len(), 2);
+    }
+
+    #[test]
+    fn search_prefix_with_no_matches_returns_empty() {
+        let document = document_with_names(&["MyScalar", "AnotherScalar"]);
+        let symbols = SymbolIndex::from_document(&document);
+
+        assert!(symbols.search_prefix("Zzz").is_empty());
+    }
+
+    #[test]
+    fn search_prefix_returns_every_matching_definition() {
+        let document = document_with_names(&["AnotherScalar", "AppleScalar", "MyScalar"]);
+        let symbols = SymbolIndex::from_document(&document);
+
+        let mut names: Vec<&str> = symbols
+            .search_prefix("a")
+            .into_iter()
+            .map(|td| match td {
+                TypeDefinition::Scalar(st) => st.name.value.as_str(),
+                TypeDefinition::Object(ot) => ot.name.value.as_str(),
+            })
+            .collect();
+        names.sort();
+
+        assert_eq!(names, vec!["AnotherScalar", "AppleScalar"]);
+    }
+}